@@ -1,23 +1,23 @@
 //! Endpoints for server-side key backups.
 
 pub mod add_backup_keys;
+pub mod add_backup_key_session;
+pub mod add_backup_key_sessions;
 pub mod create_backup;
+pub mod delete_backup;
+pub mod delete_backup_key;
+pub mod delete_backup_key_sessions;
+pub mod delete_backup_keys;
 pub mod get_backup;
 pub mod get_backup_keys;
+pub mod get_backup_key_session;
+pub mod get_backup_key_sessions;
 pub mod get_latest_backup;
 pub mod update_backup;
 
-//pub mod add_backup_key_session;
-//pub mod add_backup_key_sessions;
-//pub mod delete_backup;
-//pub mod delete_backup_key;
-//pub mod delete_backup_key_sessions;
-//pub mod delete_backup_keys;
-//pub mod get_backup_key_session;
-//pub mod get_backup_key_sessions;
-
 use crate::r0::keys::AlgorithmAndDeviceId;
 use js_int::UInt;
+use ruma_api::Raw;
 use ruma_identifiers::UserId;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -45,8 +45,16 @@ pub struct KeyData {
     forwarded_count: UInt,
     /// Whether the device backing up the key verified the device that the key is from.
     is_verified: bool,
-    /// Data about the session.
-    session_data: SessionData,
+    /// Data about the session, encrypted by the client. The homeserver doesn't deserialize it,
+    /// it merely stores and echoes it back verbatim.
+    session_data: Raw<SessionData>,
+}
+
+/// The backed-up keys for a single room, keyed by session ID.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoomKeyBackup {
+    /// A map of session IDs to key data.
+    pub sessions: BTreeMap<String, KeyData>,
 }
 
 /// The algorithm used for storing backups.
@@ -0,0 +1,37 @@
+//! [DELETE /_matrix/client/r0/room_keys/keys/{roomId}](https://matrix.org/docs/spec/client_server/unstable#delete-matrix-client-r0-room-keys-keys-roomid)
+
+use js_int::UInt;
+use ruma_api::ruma_api;
+use ruma_identifiers::RoomId;
+
+ruma_api! {
+    metadata: {
+        description: "Delete all keys from the backup for a given room.",
+        method: DELETE,
+        name: "delete_backup_key_sessions",
+        path: "/_matrix/client/r0/room_keys/keys/:room_id",
+        rate_limited: true,
+        requires_authentication: true,
+    }
+
+    request: {
+        /// The backup version from which to delete the keys.
+        #[ruma_api(query)]
+        pub version: String,
+
+        /// Room ID.
+        #[ruma_api(path)]
+        pub room_id: RoomId,
+    }
+
+    response: {
+        /// An opaque string representing stored keys in the backup. Clients can compare it with
+        /// the etag value they received in the request of their last key storage request.
+        pub etag: String,
+
+        /// The number of keys stored in the backup.
+        pub count: UInt,
+    }
+
+    error: crate::Error
+}
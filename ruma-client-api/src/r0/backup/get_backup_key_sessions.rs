@@ -0,0 +1,33 @@
+//! [GET /_matrix/client/r0/room_keys/keys/{roomId}](https://matrix.org/docs/spec/client_server/unstable#get-matrix-client-r0-room-keys-keys-roomid)
+
+use ruma_api::ruma_api;
+use ruma_identifiers::RoomId;
+
+ruma_api! {
+    metadata: {
+        description: "Retrieve all keys from the backup for a given room.",
+        method: GET,
+        name: "get_backup_key_sessions",
+        path: "/_matrix/client/r0/room_keys/keys/:room_id",
+        rate_limited: true,
+        requires_authentication: true,
+    }
+
+    request: {
+        /// The backup version to retrieve keys from.
+        #[ruma_api(query)]
+        pub version: String,
+
+        /// Room ID.
+        #[ruma_api(path)]
+        pub room_id: RoomId,
+    }
+
+    response: {
+        /// The keys for the room, keyed by session ID.
+        #[ruma_api(body)]
+        pub room_key_backup: super::RoomKeyBackup,
+    }
+
+    error: crate::Error
+}
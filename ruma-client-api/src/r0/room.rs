@@ -0,0 +1,3 @@
+//! Endpoints for room operations.
+
+pub mod report_content;
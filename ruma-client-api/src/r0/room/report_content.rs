@@ -0,0 +1,38 @@
+//! [POST /_matrix/client/r0/rooms/{roomId}/report/{eventId}](https://matrix.org/docs/spec/client_server/r0.6.1#post-matrix-client-r0-rooms-roomid-report-eventid)
+
+use js_int::Int;
+use ruma_api::ruma_api;
+use ruma_identifiers::{EventId, RoomId};
+
+ruma_api! {
+    metadata: {
+        description: "Report content as inappropriate.",
+        method: POST,
+        name: "report_content",
+        path: "/_matrix/client/r0/rooms/:room_id/report/:event_id",
+        rate_limited: false,
+        requires_authentication: true,
+    }
+
+    request: {
+        /// Room in which the event to be reported is located.
+        #[ruma_api(path)]
+        pub room_id: RoomId,
+
+        /// Event to report.
+        #[ruma_api(path)]
+        pub event_id: EventId,
+
+        /// The score to rate this content as where -100 is most offensive and 0 is inoffensive.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub score: Option<Int>,
+
+        /// The reason the content is being reported.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+
+    response: {}
+
+    error: crate::Error
+}
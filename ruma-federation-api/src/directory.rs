@@ -0,0 +1,4 @@
+//! Endpoints for the public room directory.
+
+pub mod get_public_rooms;
+pub mod get_public_rooms_filtered;
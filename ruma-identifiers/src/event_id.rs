@@ -2,7 +2,7 @@
 
 use std::num::NonZeroU8;
 
-use crate::{error::Error, parse_id, validate_id};
+use crate::{error::Error, parse_id, validate_id, RoomVersionId};
 
 /// A Matrix event ID.
 ///
@@ -44,8 +44,28 @@ use crate::{error::Error, parse_id, validate_id};
 pub struct EventId<T> {
     full_id: T,
     colon_idx: Option<NonZeroU8>,
+    format: EventIdFormat,
 }
 
+/// The format that an `EventId` follows, which is determined by the room version it belongs to.
+///
+/// See the [room versioning spec](https://matrix.org/docs/spec/rooms/v4) for more details.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventIdFormat {
+    /// The original event ID format, a localpart and the originating server name joined by a
+    /// colon, as used in room versions 1 and 2.
+    Original,
+    /// A standard (non-URL-safe) unpadded base64 encoding of a SHA-256 reference hash, as used
+    /// in room version 3.
+    Sha256Base64,
+    /// A URL-safe unpadded base64 encoding of a SHA-256 reference hash, as used in room version
+    /// 4 and later.
+    Sha256UrlSafeBase64,
+}
+
+/// The length in characters of a base64-encoded 32-byte SHA-256 hash without padding.
+const HASH_BASE64_LEN: usize = 43;
+
 impl<T> EventId<T> {
     /// Attempts to generate an `EventId` for the given origin server with a localpart consisting
     /// of 18 random ASCII characters. This should only be used for events in the original format
@@ -66,7 +86,17 @@ impl<T> EventId<T> {
         }
         let full_id = format!("${}:{}", generate_localpart(18), server_name).into();
 
-        Ok(Self { full_id, colon_idx: NonZeroU8::new(19) })
+        Ok(Self { full_id, colon_idx: NonZeroU8::new(19), format: EventIdFormat::Original })
+    }
+
+    /// Attempts to create a new Matrix event ID from a string representation, validating it
+    /// against the grammar required by the given room version instead of guessing the format
+    /// from the string's contents.
+    pub fn parse_for_version<S>(event_id: S, version: &RoomVersionId) -> Result<Self, Error>
+    where
+        S: AsRef<str> + Into<T>,
+    {
+        try_from_version(event_id, version)
     }
 
     /// Returns the event's unique ID. For the original event format as used by Matrix room
@@ -93,6 +123,15 @@ impl<T> EventId<T> {
     {
         self.colon_idx.map(|idx| &self.full_id.as_ref()[idx.get() as usize + 1..])
     }
+
+    /// Returns the format that this event ID follows.
+    ///
+    /// For an ID created with `parse_for_version`, this is the format of the given room version.
+    /// For an ID created with `TryFrom`, a hash-based format is inferred from the hash's
+    /// alphabet: a hash containing neither `-` nor `_` is reported as `Sha256Base64`.
+    pub fn format(&self) -> EventIdFormat {
+        self.format
+    }
 }
 
 /// Attempts to create a new Matrix event ID from a string representation.
@@ -106,14 +145,76 @@ where
     if event_id.as_ref().contains(':') {
         let colon_idx = parse_id(event_id.as_ref(), &['$'])?;
 
-        Ok(EventId { full_id: event_id.into(), colon_idx: Some(colon_idx) })
+        Ok(EventId { full_id: event_id.into(), colon_idx: Some(colon_idx), format: EventIdFormat::Original })
     } else {
         validate_id(event_id.as_ref(), &['$'])?;
 
-        Ok(EventId { full_id: event_id.into(), colon_idx: None })
+        // Without a room version to consult, the best this constructor can do is guess between
+        // the two hash-based formats from the hash's alphabet.
+        let format = if event_id.as_ref()[1..].chars().any(|c| c == '-' || c == '_') {
+            EventIdFormat::Sha256UrlSafeBase64
+        } else {
+            EventIdFormat::Sha256Base64
+        };
+
+        Ok(EventId { full_id: event_id.into(), colon_idx: None, format })
     }
 }
 
+/// Attempts to create a new Matrix event ID from a string representation, validating it against
+/// the grammar required by the given room version.
+///
+/// Room versions 1 and 2 use the original `$localpart:server_name` format. Room version 3 uses
+/// standard (non-URL-safe) unpadded base64 of a 32-byte SHA-256 reference hash. Room version 4
+/// and later use URL-safe unpadded base64 of the same hash.
+fn try_from_version<S, T>(event_id: S, version: &RoomVersionId) -> Result<EventId<T>, Error>
+where
+    S: AsRef<str> + Into<T>,
+{
+    match version.as_ref() {
+        "1" | "2" => {
+            let colon_idx = parse_id(event_id.as_ref(), &['$'])?;
+            Ok(EventId {
+                full_id: event_id.into(),
+                colon_idx: Some(colon_idx),
+                format: EventIdFormat::Original,
+            })
+        }
+        "3" => {
+            let hash = validate_hash_sigil(event_id.as_ref())?;
+            if !hash.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/') {
+                return Err(Error::InvalidCharacters);
+            }
+            Ok(EventId { full_id: event_id.into(), colon_idx: None, format: EventIdFormat::Sha256Base64 })
+        }
+        // Room version 4 and later.
+        _ => {
+            let hash = validate_hash_sigil(event_id.as_ref())?;
+            if !hash.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+                return Err(Error::InvalidCharacters);
+            }
+            Ok(EventId {
+                full_id: event_id.into(),
+                colon_idx: None,
+                format: EventIdFormat::Sha256UrlSafeBase64,
+            })
+        }
+    }
+}
+
+/// Validates the `$` sigil and the length of the base64 hash that follows it, returning the hash
+/// itself (without the sigil) for further grammar-specific checks.
+fn validate_hash_sigil(event_id: &str) -> Result<&str, Error> {
+    validate_id(event_id, &['$'])?;
+
+    let hash = &event_id[1..];
+    if hash.len() != HASH_BASE64_LEN {
+        return Err(Error::InvalidCharacters);
+    }
+
+    Ok(hash)
+}
+
 common_impls!(EventId, try_from, "a Matrix event ID");
 
 #[cfg(test)]
@@ -296,4 +397,75 @@ mod tests {
             Error::InvalidServerName
         );
     }
+
+    #[test]
+    fn parse_for_version_original() {
+        use crate::RoomVersionId;
+
+        assert_eq!(
+            EventId::parse_for_version("$39hvsi03hlne:example.com", &RoomVersionId::try_from("1").unwrap())
+                .expect("Failed to create EventId.")
+                .as_ref(),
+            "$39hvsi03hlne:example.com"
+        );
+    }
+
+    #[test]
+    fn parse_for_version_sha256_base64() {
+        use crate::RoomVersionId;
+
+        assert_eq!(
+            EventId::parse_for_version(
+                "$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk",
+                &RoomVersionId::try_from("3").unwrap()
+            )
+            .expect("Failed to create EventId.")
+            .as_ref(),
+            "$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk"
+        );
+    }
+
+    #[test]
+    fn parse_for_version_sha256_url_safe_base64() {
+        use crate::RoomVersionId;
+
+        assert_eq!(
+            EventId::parse_for_version(
+                "$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg",
+                &RoomVersionId::try_from("4").unwrap()
+            )
+            .expect("Failed to create EventId.")
+            .as_ref(),
+            "$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg"
+        );
+    }
+
+    #[test]
+    fn format_is_recorded_not_guessed() {
+        use crate::RoomVersionId;
+
+        // This hash happens to contain neither `-` nor `_`, so guessing the format from its
+        // alphabet alone would misclassify it as `Sha256Base64`.
+        let event_id = EventId::parse_for_version(
+            "$Rqnc1F9dvnEYJTyHq9iKxU2bZ1CI92QkuZq3a5lr5Zg",
+            &RoomVersionId::try_from("5").unwrap(),
+        )
+        .expect("Failed to create EventId.");
+
+        assert_eq!(event_id.format(), super::EventIdFormat::Sha256UrlSafeBase64);
+    }
+
+    #[test]
+    fn parse_for_version_rejects_wrong_format() {
+        use crate::RoomVersionId;
+
+        assert_eq!(
+            EventId::parse_for_version(
+                "$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk",
+                &RoomVersionId::try_from("4").unwrap()
+            )
+            .unwrap_err(),
+            Error::InvalidCharacters
+        );
+    }
 }
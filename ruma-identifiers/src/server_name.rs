@@ -0,0 +1,183 @@
+//! Matrix server names.
+
+use std::{net::Ipv6Addr, num::NonZeroU8};
+
+use crate::error::Error;
+
+/// A Matrix server name.
+///
+/// A server name is a DNS name, an IPv4 address, or a bracketed IPv6 literal, optionally
+/// followed by a `:` and a port number.
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// # use ruma_identifiers::ServerName;
+/// assert_eq!(ServerName::try_from("example.com").unwrap().as_ref(), "example.com");
+/// assert_eq!(ServerName::try_from("[::1]:8448").unwrap().as_ref(), "[::1]:8448");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ServerName<T> {
+    full_name: T,
+    colon_idx: Option<NonZeroU8>,
+}
+
+impl<T> ServerName<T> {
+    /// Returns the hostname of the server name, without the port.
+    pub fn hostname(&self) -> &str
+    where
+        T: AsRef<str>,
+    {
+        let full_name = self.full_name.as_ref();
+
+        match self.colon_idx {
+            Some(idx) => &full_name[..idx.get() as usize],
+            None => full_name,
+        }
+    }
+
+    /// Returns the port of the server name, if one was given explicitly.
+    pub fn port(&self) -> Option<u16>
+    where
+        T: AsRef<str>,
+    {
+        self.colon_idx.map(|idx| {
+            self.full_name.as_ref()[idx.get() as usize + 1..]
+                .parse()
+                .expect("a validated ServerName has a valid port")
+        })
+    }
+}
+
+/// Attempts to create a new `ServerName` from a string representation.
+fn try_from<S, T>(server_name: S) -> Result<ServerName<T>, Error>
+where
+    S: AsRef<str> + Into<T>,
+{
+    let colon_idx = parse_server_name(server_name.as_ref())?;
+
+    Ok(ServerName { full_name: server_name.into(), colon_idx })
+}
+
+/// Validates the grammar of a Matrix server name (a DNS name, IPv4 address, or bracketed IPv6
+/// literal, with an optional `:port`) and returns the index of the `:` that precedes the port,
+/// if a port was given.
+pub(crate) fn parse_server_name(server_name: &str) -> Result<Option<NonZeroU8>, Error> {
+    if server_name.is_empty() || server_name.len() > crate::MAX_BYTES {
+        return Err(Error::InvalidServerName);
+    }
+
+    if server_name.starts_with('[') {
+        let bracket_idx = server_name.find(']').ok_or(Error::InvalidServerName)?;
+        validate_ipv6_literal(&server_name[1..bracket_idx])?;
+
+        let rest = &server_name[bracket_idx + 1..];
+        if rest.is_empty() {
+            return Ok(None);
+        }
+
+        let port = rest.strip_prefix(':').ok_or(Error::InvalidServerName)?;
+        validate_port(port)?;
+
+        Ok(NonZeroU8::new((bracket_idx + 1) as u8))
+    } else {
+        match server_name.find(':') {
+            Some(colon_idx) => {
+                validate_dns_name_or_ipv4(&server_name[..colon_idx])?;
+                validate_port(&server_name[colon_idx + 1..])?;
+
+                Ok(NonZeroU8::new(colon_idx as u8))
+            }
+            None => {
+                validate_dns_name_or_ipv4(server_name)?;
+
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Checks that a bracketed literal contains a valid IPv6 address.
+fn validate_ipv6_literal(addr: &str) -> Result<(), Error> {
+    addr.parse::<Ipv6Addr>().map(|_| ()).map_err(|_| Error::InvalidServerName)
+}
+
+/// Checks that a host is a valid DNS name or IPv4 dotted-quad. Matrix doesn't impose anything
+/// stricter than "a plausible hostname" on the DNS name case.
+fn validate_dns_name_or_ipv4(host: &str) -> Result<(), Error> {
+    if host.is_empty() || !host.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+    {
+        return Err(Error::InvalidServerName);
+    }
+
+    Ok(())
+}
+
+/// Checks that a string is a valid port number.
+fn validate_port(port: &str) -> Result<(), Error> {
+    if port.is_empty() || !port.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::InvalidServerName);
+    }
+
+    port.parse::<u16>().map(|_| ()).map_err(|_| Error::InvalidServerName)
+}
+
+common_impls!(ServerName, try_from, "a Matrix server name");
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::error::Error;
+
+    type ServerName = super::ServerName<Box<str>>;
+
+    #[test]
+    fn valid_dns_name() {
+        assert_eq!(ServerName::try_from("example.com").unwrap().as_ref(), "example.com");
+    }
+
+    #[test]
+    fn valid_dns_name_with_port() {
+        let server_name = ServerName::try_from("example.com:8448").unwrap();
+        assert_eq!(server_name.as_ref(), "example.com:8448");
+        assert_eq!(server_name.hostname(), "example.com");
+        assert_eq!(server_name.port(), Some(8448));
+    }
+
+    #[test]
+    fn valid_ipv4_literal() {
+        let server_name = ServerName::try_from("127.0.0.1").unwrap();
+        assert_eq!(server_name.hostname(), "127.0.0.1");
+        assert_eq!(server_name.port(), None);
+    }
+
+    #[test]
+    fn valid_ipv6_literal_with_port() {
+        let server_name = ServerName::try_from("[::1]:8448").unwrap();
+        assert_eq!(server_name.as_ref(), "[::1]:8448");
+        assert_eq!(server_name.hostname(), "[::1]");
+        assert_eq!(server_name.port(), Some(8448));
+    }
+
+    #[test]
+    fn valid_ipv6_literal_without_port() {
+        let server_name = ServerName::try_from("[::1]").unwrap();
+        assert_eq!(server_name.hostname(), "[::1]");
+        assert_eq!(server_name.port(), None);
+    }
+
+    #[test]
+    fn missing_ipv6_brackets() {
+        assert_eq!(ServerName::try_from("::1").unwrap_err(), Error::InvalidServerName);
+    }
+
+    #[test]
+    fn invalid_port() {
+        assert_eq!(ServerName::try_from("example.com:notaport").unwrap_err(), Error::InvalidServerName);
+    }
+
+    #[test]
+    fn empty_server_name() {
+        assert_eq!(ServerName::try_from("").unwrap_err(), Error::InvalidServerName);
+    }
+}
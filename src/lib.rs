@@ -31,16 +31,19 @@
 #[cfg_attr(feature = "diesel", macro_use)]
 extern crate diesel;
 
-use std::fmt::{Formatter, Result as FmtResult};
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    num::NonZeroU8,
+};
 
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
-use url::Url;
 
 pub use url::Host;
 
 pub use crate::{
-    error::Error, event_id::EventId, room_alias_id::RoomAliasId, room_id::RoomId,
-    room_id_or_room_alias_id::RoomIdOrAliasId, room_version_id::RoomVersionId, user_id::UserId,
+    error::Error, event_id::{EventId, EventIdFormat}, room_alias_id::RoomAliasId,
+    room_id::RoomId, room_id_or_room_alias_id::RoomIdOrAliasId, room_version_id::RoomVersionId,
+    server_name::ServerName, user_id::UserId,
 };
 
 #[cfg(feature = "diesel")]
@@ -51,6 +54,7 @@ mod room_alias_id;
 mod room_id;
 mod room_id_or_room_alias_id;
 mod room_version_id;
+mod server_name;
 mod user_id;
 
 /// All identifiers must be 255 bytes or less.
@@ -86,8 +90,9 @@ fn generate_localpart(length: usize) -> String {
         .collect()
 }
 
-/// Checks if an identifier is within the acceptable byte lengths.
-fn validate_id(id: &str) -> Result<(), Error> {
+/// Checks if an identifier is within the acceptable byte lengths and starts with one of the
+/// sigils that are valid for it.
+fn validate_id(id: &str, valid_sigils: &[char]) -> Result<(), Error> {
     if id.len() > MAX_BYTES {
         return Err(Error::MaximumLengthExceeded);
     }
@@ -96,33 +101,38 @@ fn validate_id(id: &str) -> Result<(), Error> {
         return Err(Error::MinimumLengthNotSatisfied);
     }
 
-    Ok(())
-}
-
-/// Parses the localpart, host, and port from a string identifier.
-fn parse_id(required_sigil: char, id: &str) -> Result<(&str, Host, u16), Error> {
-    validate_id(id)?;
-
-    if !id.starts_with(required_sigil) {
+    if !valid_sigils.iter().any(|&sigil| id.starts_with(sigil)) {
         return Err(Error::MissingSigil);
     }
 
-    let delimiter_index = match id.find(':') {
+    Ok(())
+}
+
+/// Parses and validates a colon-delimited string identifier, returning the index of the `:` that
+/// separates the localpart from the server name.
+///
+/// The server name that follows the colon is validated using the same grammar as
+/// [`ServerName`], so every identifier type that embeds a server name (rather than a bare,
+/// allocation-free one) rejects malformed hosts consistently, including bracketed IPv6 literals.
+/// Unlike the old `Url`-based check this replaced, it doesn't accept trailing path, query, or
+/// fragment junk after the host. It also no longer leans on `Url`'s IDNA handling, so
+/// internationalized (non-ASCII) hostnames that used to slip through the fallback are rejected
+/// until `ServerName`'s grammar grows support for them.
+fn parse_id(id: &str, valid_sigils: &[char]) -> Result<NonZeroU8, Error> {
+    validate_id(id, valid_sigils)?;
+
+    let colon_idx = match id.find(':') {
         Some(index) => index,
         None => return Err(Error::MissingDelimiter),
     };
 
-    let localpart = &id[1..delimiter_index];
-    let raw_host = &id[delimiter_index + SIGIL_BYTES..];
-    let url_string = format!("https://{}", raw_host);
-    let url = Url::parse(&url_string)?;
+    let server_name = &id[colon_idx + SIGIL_BYTES..];
+    server_name::parse_server_name(server_name)?;
 
-    let host = match url.host() {
-        Some(host) => host.to_owned(),
-        None => return Err(Error::InvalidHost),
-    };
-
-    let port = url.port().unwrap_or(443);
+    NonZeroU8::new(colon_idx as u8).ok_or(Error::MissingSigil)
+}
 
-    Ok((localpart, host, port))
+/// Checks whether a string is a valid Matrix server name.
+fn is_valid_server_name(s: &str) -> bool {
+    server_name::parse_server_name(s).is_ok()
 }